@@ -0,0 +1,59 @@
+//! Fiat currency conversion, pivoting through USD.
+//!
+//! Price providers all quote in USD; this module fetches USD-based cross
+//! rates once per refresh and converts those USD values into whatever
+//! `base_currency` the portfolio is configured for. A failed fetch keeps
+//! the last known-good table instead of zeroing out the portfolio.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::Deserialize;
+
+/// USD -> currency factors, e.g. `rates["EUR"] == 0.92`.
+#[derive(Debug, Clone)]
+pub struct FxTable {
+    rates: HashMap<String, f64>,
+}
+
+impl FxTable {
+    /// A table that only knows about USD, used until the first fetch
+    /// succeeds.
+    pub fn usd_only() -> Self {
+        let mut rates = HashMap::new();
+        rates.insert("USD".to_string(), 1.0);
+        Self { rates }
+    }
+
+    /// Converts a USD value into `currency`. Falls back to the USD value
+    /// unchanged if the currency isn't in the table.
+    pub fn convert(&self, usd_value: f64, currency: &str) -> f64 {
+        match self.rates.get(currency) {
+            Some(rate) => usd_value * rate,
+            None => usd_value,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ExchangeRateResp {
+    rates: HashMap<String, f64>,
+}
+
+/// Fetches current USD cross rates. Returns an error rather than an empty
+/// table on failure so the caller can decide to keep its last good rates.
+pub async fn fetch_rates(client: &Client) -> Result<FxTable> {
+    let resp: ExchangeRateResp = client
+        .get("https://api.exchangerate.host/latest?base=USD")
+        .send()
+        .await
+        .context("requesting FX rates")?
+        .json()
+        .await
+        .context("parsing FX rates")?;
+
+    let mut rates = resp.rates;
+    rates.insert("USD".to_string(), 1.0);
+    Ok(FxTable { rates })
+}
@@ -0,0 +1,80 @@
+//! Per-symbol price history, persisted to disk so trend data survives
+//! restarts instead of the TUI starting from a blank slate every time.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// How many points to keep per symbol, in memory and on disk.
+const MAX_POINTS: usize = 240;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PricePoint {
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub price: f64,
+}
+
+/// Ring-buffered price history per symbol, backed by a JSON file on disk.
+pub struct PriceHistory {
+    path: PathBuf,
+    series: HashMap<String, VecDeque<PricePoint>>,
+}
+
+impl PriceHistory {
+    /// Loads history from `path` if it exists and parses; starts empty
+    /// otherwise, so a missing or corrupt file is never fatal.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let series = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        Self { path, series }
+    }
+
+    pub fn record(&mut self, symbol: &str, price: f64) {
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let buf = self.series.entry(symbol.to_string()).or_default();
+        buf.push_back(PricePoint { at, price });
+        while buf.len() > MAX_POINTS {
+            buf.pop_front();
+        }
+    }
+
+    /// Points for `symbol` as `(index, price)` pairs, ready for a
+    /// `ratatui::widgets::Chart` dataset.
+    pub fn points(&self, symbol: &str) -> Vec<(f64, f64)> {
+        self.series
+            .get(symbol)
+            .map(|buf| {
+                buf.iter()
+                    .enumerate()
+                    .map(|(i, p)| (i as f64, p.price))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Percent change between the oldest and newest recorded price.
+    pub fn session_change_pct(&self, symbol: &str) -> Option<f64> {
+        let buf = self.series.get(symbol)?;
+        let first = buf.front()?.price;
+        let last = buf.back()?.price;
+        if first == 0.0 {
+            return None;
+        }
+        Some((last - first) / first * 100.0)
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let raw = serde_json::to_string(&self.series)?;
+        std::fs::write(&self.path, raw)
+    }
+}
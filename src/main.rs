@@ -1,11 +1,50 @@
-use std::{io::Stdout, time::Duration};
+mod fx;
+mod history;
+mod providers;
+mod quote;
+mod streaming;
+
+use std::{collections::HashMap, io::Stdout, time::{Duration, Instant}};
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use tokio::{task, time};
+use tokio::{sync::watch, time};
 use reqwest::Client;
-use ratatui::{prelude::*, backend::CrosstermBackend, widgets::{Block, Borders, Row, Table}};
+use ratatui::{
+    prelude::*,
+    backend::CrosstermBackend,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph, Row, Table},
+};
 use crossterm::{execute, event, terminal};
 
+use fx::FxTable;
+use history::PriceHistory;
+use providers::{build_provider, ProviderKind, ProviderSettings};
+use quote::{Quote, QuoteCache};
+
+const HISTORY_PATH: &str = "price_history.json";
+
+/// A quote is considered stale after this long without a successful
+/// refetch — twice the REST poll interval.
+const STALE_TTL: Duration = Duration::from_secs(60);
+
+/// Which panel the TUI is currently showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewMode {
+    Table,
+    Detail,
+}
+
+/// How the table orders its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortMode {
+    /// Portfolio order, as configured.
+    None,
+    /// Highest value first.
+    Value,
+    /// Highest session %-change first.
+    Change,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "lowercase")]
 enum AssetType {
@@ -20,60 +59,68 @@ struct AssetConfig {
     symbol: String,
     quantity: f64,
     api: Option<String>,
+    /// Overrides the portfolio-wide provider for this one asset.
+    provider: Option<ProviderKind>,
+    /// USD price paid per unit, if known. Drives the unrealized P/L column.
+    cost_basis: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Portfolio {
+    /// Default backend for assets that don't set their own `provider`.
+    #[serde(default)]
+    provider: ProviderKind,
+    /// CoinMarketCap API key. Falls back to `SODATTE_CMC_API_KEY` if unset.
+    #[serde(default)]
+    api_key: Option<String>,
+    /// Fixed prices for assets using `provider = "forced"`.
+    #[serde(default)]
+    forced_prices: HashMap<String, f64>,
+    /// Currency prices are displayed in; quotes are converted from USD.
+    #[serde(default = "default_base_currency")]
+    base_currency: String,
     assets: Vec<AssetConfig>,
 }
 
+fn default_base_currency() -> String {
+    "USD".to_string()
+}
+
 // Data retrieval
-async fn load_config(path: &str) -> Result<Vec<AssetConfig>> {
+async fn load_config(path: &str) -> Result<Portfolio> {
     let raw = tokio::fs::read_to_string(path)
         .await
         .with_context(|| format!("reading {path}"))?;
-    let portfolio: Portfolio = toml::from_str(&raw).context("parsing TOML portfolio file")?;
-    Ok(portfolio.assets)
+    toml::from_str(&raw).context("parsing TOML portfolio file")
 }
 
+fn provider_settings(portfolio: &Portfolio) -> ProviderSettings {
+    ProviderSettings {
+        coinmarketcap_api_key: portfolio
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("SODATTE_CMC_API_KEY").ok()),
+        forced_prices: portfolio.forced_prices.clone(),
+    }
+}
+
+/// Fetches a quote from an asset's custom `api` URL, or the example
+/// placeholder endpoint for stocks/commodities if none is configured.
+/// Crypto assets without a custom `api` are priced via `PriceProvider`
+/// instead; see `fetch_portfolio`.
 async fn fetch_price(client: &Client, a: &AssetConfig) -> Result<f64> {
-    const API_KEY: &str = "0ac51e33-41f2-414a-90c4-3301efbbce7c";
     let url = if let Some(custom) = &a.api {
         custom.clone()
     } else {
         match a.kind {
             AssetType::Stock => format!("https://example.com/stock/{}", a.symbol),
-            AssetType::Crypto => format!(
-                "https://pro-api.coinmarketcap.com/v1/cryptocurrency/quotes/latest?symbol={symbol}&CMC_PRO_API_KEY={key}",
-                symbol = a.symbol,
-                key = API_KEY
-            ),
             AssetType::Commodity => format!("https://example.com/commodity/{}", a.symbol),
+            AssetType::Crypto => {
+                unreachable!("crypto without a custom api is priced via PriceProvider")
+            }
         }
     };
 
-    #[derive(Deserialize)]
-    struct CmcRespInner {
-        #[serde(rename = "quote")]
-        quote: std::collections::HashMap<String, serde_json::Value>,
-    }
-
-    #[derive(Deserialize)]
-    struct CmcResponse {
-        data: std::collections::HashMap<String, CmcRespInner>,
-    }
-
-    if matches!(a.kind, AssetType::Crypto) {
-        let resp: CmcResponse = client.get(url).send().await?.json().await?;
-        let inner = resp
-            .data
-            .get(&a.symbol)
-            .context("symbol missing")?;
-        let usd = inner.quote.get("USD").context("USD quote missing")?;
-        let price = usd.get("price").context("price missing")?.as_f64().context("not f64")?;
-        return Ok(price);
-    }
-
     #[derive(Deserialize)]
     struct Resp {
         price: f64,
@@ -83,19 +130,85 @@ async fn fetch_price(client: &Client, a: &AssetConfig) -> Result<f64> {
     Ok(price)
 }
 
-async fn refresh_portfolio(client: &Client, cfg: &[AssetConfig]) -> Vec<(AssetConfig, f64)> {
+/// Fetches a fresh price per asset, or `None` where the fetch failed, plus
+/// any provider error messages encountered along the way. Stderr is masked
+/// by the TUI's alternate screen once the terminal is set up, so errors are
+/// returned for the caller to show on the on-screen status line instead of
+/// being printed directly.
+async fn fetch_portfolio(
+    client: &Client,
+    portfolio: &Portfolio,
+    settings: &ProviderSettings,
+) -> (Vec<(AssetConfig, Option<f64>)>, Vec<String>) {
+    let cfg = &portfolio.assets;
+
+    // Crypto assets are grouped by resolved provider so each backend gets a
+    // single batched `quotes` call instead of one request per asset.
+    let mut by_provider: HashMap<ProviderKind, Vec<String>> = HashMap::new();
+    for a in cfg.iter().filter(|a| matches!(a.kind, AssetType::Crypto) && a.api.is_none()) {
+        let kind = a.provider.unwrap_or(portfolio.provider);
+        by_provider.entry(kind).or_default().push(a.symbol.clone());
+    }
+
+    let mut crypto_quotes: HashMap<String, f64> = HashMap::new();
+    let mut errors = Vec::new();
+    for (kind, symbols) in by_provider {
+        let provider = build_provider(kind, client.clone(), settings);
+        match provider.quotes(&symbols).await {
+            Ok(quotes) => crypto_quotes.extend(quotes),
+            Err(e) => errors.push(format!("price provider error: {e:#}")),
+        }
+    }
+
     let tasks = cfg.iter().cloned().map(|asset| {
         let c = client.clone();
-        task::spawn(async move {
-            let price = fetch_price(&c, &asset).await.unwrap_or(0.0);
+        // Crypto assets without a custom `api` are priced solely via
+        // `crypto_quotes` above; `fetch_price` must never be called for
+        // them; it has no URL to hit for that case and panics.
+        let priced_via_provider = matches!(asset.kind, AssetType::Crypto) && asset.api.is_none();
+        let cached_crypto = priced_via_provider
+            .then(|| crypto_quotes.get(&asset.symbol).copied())
+            .flatten();
+        async move {
+            let price = if priced_via_provider {
+                cached_crypto
+            } else {
+                fetch_price(&c, &asset).await.ok()
+            };
             (asset, price)
-        })
+        }
     });
 
-    futures::future::join_all(tasks)
-        .await
-        .into_iter()
-        .map(|r| r.expect("task panicked"))
+    (futures::future::join_all(tasks).await, errors)
+}
+
+/// Folds freshly fetched prices into `cache`; a failed fetch (`None`) just
+/// leaves the previous quote in place rather than overwriting it. This is
+/// what lets a quote go stale instead of vanishing — it only holds end to
+/// end because `fetch_portfolio` returns `None` for a missing crypto quote
+/// rather than panicking (see its doc comment).
+fn update_cache(cache: &mut QuoteCache, fetched: &[(AssetConfig, Option<f64>)]) {
+    for (asset, price) in fetched {
+        if let Some(p) = price {
+            cache.update(&asset.symbol, *p);
+        }
+    }
+}
+
+/// Current cached quote for every configured asset, in order. Assets never
+/// successfully fetched read as a zero price rather than panicking.
+fn quote_rows(portfolio: &Portfolio, cache: &QuoteCache) -> Vec<(AssetConfig, Quote)> {
+    portfolio
+        .assets
+        .iter()
+        .cloned()
+        .map(|asset| {
+            let quote = cache.get(&asset.symbol).unwrap_or(Quote {
+                price: 0.0,
+                fetched_at: Instant::now(),
+            });
+            (asset, quote)
+        })
         .collect()
 }
 
@@ -122,56 +235,429 @@ fn restore_terminal(mut term: Term) -> Result<()> {
     Ok(())
 }
 
-fn draw_ui(f: &mut Frame, rows: &[(AssetConfig, f64)]) {
-    let header = Row::new(["Type", "Symbol", "Qty", "Price", "Value"]).style(
-        Style::default().add_modifier(Modifier::BOLD),
-    );
+/// Per-row figures computed once in portfolio order, reused for both the
+/// totals and the optional sort so every row agrees on the same numbers.
+struct RowFigures {
+    value: f64,
+    pl: Option<f64>,
+    change_pct: f64,
+}
 
-    let body = rows.iter().map(|(a, price)| {
-        Row::new([
-            format!("{:?}", a.kind),
-            a.symbol.clone(),
-            a.quantity.to_string(),
-            format!("{:.2}", price),
-            format!("{:.2}", price * a.quantity),
-        ])
-    });
+fn row_figures(
+    rows: &[(AssetConfig, Quote)],
+    fx: &FxTable,
+    base_currency: &str,
+    history: &PriceHistory,
+) -> Vec<RowFigures> {
+    rows.iter()
+        .map(|(a, quote)| {
+            let price = fx.convert(quote.price, base_currency);
+            let value = price * a.quantity;
+            let pl = a.cost_basis.map(|basis| {
+                let basis = fx.convert(basis, base_currency);
+                (price - basis) * a.quantity
+            });
+            let change_pct = history.session_change_pct(&a.symbol).unwrap_or(0.0);
+            RowFigures {
+                value,
+                pl,
+                change_pct,
+            }
+        })
+        .collect()
+}
+
+fn sorted_row_order(figures: &[RowFigures], sort: SortMode) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..figures.len()).collect();
+    match sort {
+        SortMode::None => {}
+        SortMode::Value => {
+            order.sort_by(|&a, &b| figures[b].value.total_cmp(&figures[a].value));
+        }
+        SortMode::Change => {
+            order.sort_by(|&a, &b| figures[b].change_pct.total_cmp(&figures[a].change_pct));
+        }
+    }
+    order
+}
+
+fn draw_table(
+    f: &mut Frame,
+    rows: &[(AssetConfig, Quote)],
+    figures: &[RowFigures],
+    order: &[usize],
+    selected: usize,
+    fx: &FxTable,
+    base_currency: &str,
+    status: Option<&str>,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+        .split(f.size());
+
+    let header = Row::new([
+        "Type".to_string(),
+        "Symbol".to_string(),
+        "Qty".to_string(),
+        format!("Price ({base_currency})"),
+        format!("Value ({base_currency})"),
+        "Alloc%".to_string(),
+        format!("P/L ({base_currency})"),
+    ])
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let total_value: f64 = figures.iter().map(|f| f.value).sum();
+    let total_pl: f64 = figures.iter().filter_map(|f| f.pl).sum();
+
+    let body: Vec<Row> = order
+        .iter()
+        .map(|&i| {
+            let (a, quote) = &rows[i];
+            let fig = &figures[i];
+            let price = fx.convert(quote.price, base_currency);
+
+            let stale = quote.is_stale(STALE_TTL);
+            let price_cell = if stale {
+                format!("{price:.2} ({}s old)", quote.age().as_secs())
+            } else {
+                format!("{price:.2}")
+            };
+
+            let alloc_pct = if total_value > 0.0 {
+                fig.value / total_value * 100.0
+            } else {
+                0.0
+            };
+            let pl_cell = match fig.pl {
+                Some(pl) => format!("{pl:+.2}"),
+                None => "-".to_string(),
+            };
+
+            let row = Row::new([
+                format!("{:?}", a.kind),
+                a.symbol.clone(),
+                a.quantity.to_string(),
+                price_cell,
+                format!("{:.2}", fig.value),
+                format!("{alloc_pct:.1}%"),
+                pl_cell,
+            ]);
+
+            let mut style = Style::default();
+            if stale {
+                style = style.fg(Color::Yellow).add_modifier(Modifier::DIM);
+            }
+            if i == selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            row.style(style)
+        })
+        .collect();
 
     let widths = [
         Constraint::Length(8),
         Constraint::Length(10),
         Constraint::Length(10),
-        Constraint::Length(12),
+        Constraint::Length(20),
+        Constraint::Length(14),
+        Constraint::Length(8),
         Constraint::Length(14),
     ];
 
-    let table = Table::new(body, widths)
-        .header(header)
-        .block(Block::default().title("Portfolio").borders(Borders::ALL));
+    let table = Table::new(body, widths).header(header).block(
+        Block::default()
+            .title("Portfolio (↑/↓ select, c: chart, v/s: sort, q: quit)")
+            .borders(Borders::ALL),
+    );
+
+    f.render_widget(table, chunks[0]);
+
+    let footer = Paragraph::new(format!(
+        "Total: {total_value:.2} {base_currency}   P/L: {total_pl:+.2} {base_currency}"
+    ));
+    f.render_widget(footer, chunks[1]);
+
+    let status_line =
+        Paragraph::new(status.unwrap_or_default()).style(Style::default().fg(Color::Red));
+    f.render_widget(status_line, chunks[2]);
+}
+
+fn draw_detail(
+    f: &mut Frame,
+    asset: &AssetConfig,
+    quote: Quote,
+    history: &PriceHistory,
+    fx: &FxTable,
+    base_currency: &str,
+) {
+    let price = fx.convert(quote.price, base_currency);
+    // history stores raw USD prices; convert every point so the plotted
+    // curve and axis bounds are in the same currency as the title above it.
+    let points: Vec<(f64, f64)> = history
+        .points(&asset.symbol)
+        .into_iter()
+        .map(|(x, usd)| (x, fx.convert(usd, base_currency)))
+        .collect();
+    let change = history.session_change_pct(&asset.symbol).unwrap_or(0.0);
+
+    let min = points.iter().map(|(_, p)| *p).fold(f64::MAX, f64::min);
+    let max = points.iter().map(|(_, p)| *p).fold(f64::MIN, f64::max);
+    let len = points.len().saturating_sub(1) as f64;
+
+    let dataset = Dataset::default()
+        .name(asset.symbol.as_str())
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&points);
+
+    let staleness = if quote.is_stale(STALE_TTL) {
+        format!(", STALE ({}s old)", quote.age().as_secs())
+    } else {
+        String::new()
+    };
+
+    let chart = Chart::new(vec![dataset])
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} — {:.2} {}, session {:+.2}%{staleness} (Esc/c: back)",
+                    asset.symbol, price, base_currency, change
+                ))
+                .borders(Borders::ALL),
+        )
+        .x_axis(Axis::default().bounds([0.0, len.max(1.0)]))
+        .y_axis(Axis::default().bounds(if points.is_empty() {
+            [0.0, 1.0]
+        } else {
+            [min, max.max(min + f64::EPSILON)]
+        }));
+
+    f.render_widget(chart, f.size());
+}
+
+fn draw_ui(
+    f: &mut Frame,
+    rows: &[(AssetConfig, Quote)],
+    figures: &[RowFigures],
+    order: &[usize],
+    history: &PriceHistory,
+    view: ViewMode,
+    selected: usize,
+    fx: &FxTable,
+    base_currency: &str,
+    status: Option<&str>,
+) {
+    match view {
+        ViewMode::Table => draw_table(f, rows, figures, order, selected, fx, base_currency, status),
+        ViewMode::Detail => {
+            if let Some((asset, quote)) = rows.get(selected) {
+                draw_detail(f, asset, *quote, history, fx, base_currency);
+            } else {
+                draw_table(f, rows, figures, order, selected, fx, base_currency, status);
+            }
+        }
+    }
+}
+
+/// Symbols that should ride the live WebSocket feed instead of waiting on
+/// the REST poll. Only CoinCap has a streaming backend today.
+fn streamed_symbols(portfolio: &Portfolio) -> Vec<String> {
+    portfolio
+        .assets
+        .iter()
+        .filter(|a| {
+            a.api.is_none()
+                && matches!(a.kind, AssetType::Crypto)
+                && a.provider.unwrap_or(portfolio.provider) == ProviderKind::CoinCap
+        })
+        .map(|a| a.symbol.clone())
+        .collect()
+}
+
+fn apply_streamed_prices(cache: &mut QuoteCache, streamed: &HashMap<String, f64>) {
+    for (symbol, price) in streamed {
+        cache.update(symbol, *price);
+    }
+}
+
+/// Resolves once a streamed price update arrives, or never if there's no
+/// live feed to watch, so it can sit in a `tokio::select!` unconditionally.
+async fn next_stream_tick(rx: &mut Option<watch::Receiver<HashMap<String, f64>>>) {
+    match rx {
+        Some(rx) => {
+            let _ = rx.changed().await;
+        }
+        None => std::future::pending().await,
+    }
+}
 
-    f.render_widget(table, f.size());
+/// Joins a refresh cycle's error messages into one status line, so a
+/// provider error and an FX error in the same cycle are both visible
+/// instead of the first being silently dropped.
+fn join_status(messages: Vec<String>) -> Option<String> {
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Records each row's price and persists it. Returns an error message
+/// instead of printing it directly, for the same reason as `fetch_portfolio`.
+fn record_history(history: &mut PriceHistory, rows: &[(AssetConfig, Quote)]) -> Option<String> {
+    for (asset, quote) in rows {
+        history.record(&asset.symbol, quote.price);
+    }
+    history
+        .save()
+        .err()
+        .map(|e| format!("failed to persist price history: {e}"))
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cfg = load_config("portfolio.toml").await?;
+    let portfolio = load_config("portfolio.toml").await?;
+    let settings = provider_settings(&portfolio);
     let client = Client::builder().user_agent("sodatte/0.1").build()?;
 
     let mut term = setup_terminal()?;
+    let mut history = PriceHistory::load(HISTORY_PATH);
+    let mut view = ViewMode::Table;
+    let mut selected = 0usize;
+    let mut sort = SortMode::None;
+    let mut fx = FxTable::usd_only();
+    let mut cache = QuoteCache::default();
+    // Last error from a refresh cycle, shown on the table's status line
+    // instead of eprintln'd — stderr is masked once the alternate screen
+    // is up, which used to corrupt the display on every failed fetch.
+    let mut status: Option<String> = None;
+
+    let stream_symbols = streamed_symbols(&portfolio);
+    let mut stream_rx = (!stream_symbols.is_empty())
+        .then(|| streaming::spawn_coincap_stream(client.clone(), stream_symbols));
+
+    let (fetched, mut messages) = fetch_portfolio(&client, &portfolio, &settings).await;
+    update_cache(&mut cache, &fetched);
+    let mut rows = quote_rows(&portfolio, &cache);
+    messages.extend(record_history(&mut history, &rows));
+    if let Ok(new_fx) = fx::fetch_rates(&client).await {
+        fx = new_fx;
+    }
+    status = join_status(messages);
+    {
+        let figures = row_figures(&rows, &fx, &portfolio.base_currency, &history);
+        let order = sorted_row_order(&figures, sort);
+        term.draw(|f| {
+            draw_ui(
+                f,
+                &rows,
+                &figures,
+                &order,
+                &history,
+                view,
+                selected,
+                &fx,
+                &portfolio.base_currency,
+                status.as_deref(),
+            )
+        })?;
+    }
 
-    let mut ticker = time::interval(Duration::from_secs(30));
+    // First tick already covered by the initial refresh above.
+    let mut ticker = time::interval_at(time::Instant::now() + Duration::from_secs(30), Duration::from_secs(30));
 
     loop {
-        ticker.tick().await;
+        tokio::select! {
+            _ = ticker.tick() => {
+                let (fetched, mut messages) = fetch_portfolio(&client, &portfolio, &settings).await;
+                update_cache(&mut cache, &fetched);
+                rows = quote_rows(&portfolio, &cache);
+                messages.extend(record_history(&mut history, &rows));
+                // A failed fetch keeps the last known-good rates rather
+                // than zeroing out every converted value.
+                match fx::fetch_rates(&client).await {
+                    Ok(new_fx) => fx = new_fx,
+                    Err(e) => messages.push(format!("FX rate fetch failed, reusing last rates: {e:#}")),
+                }
+                status = join_status(messages);
+            }
+            _ = next_stream_tick(&mut stream_rx) => {
+                // Debounce: let a short burst of trades settle before redrawing.
+                time::sleep(Duration::from_millis(150)).await;
+                if let Some(rx) = &stream_rx {
+                    apply_streamed_prices(&mut cache, &rx.borrow());
+                }
+                rows = quote_rows(&portfolio, &cache);
+                if let Some(e) = record_history(&mut history, &rows) {
+                    status = Some(e);
+                }
+            }
+        }
 
-        let rows = refresh_portfolio(&client, &cfg).await;
+        // Recomputed every iteration so navigation and rendering always
+        // agree on the currently displayed order, even right after `sort`
+        // changes.
+        let figures = row_figures(&rows, &fx, &portfolio.base_currency, &history);
+        let order = sorted_row_order(&figures, sort);
 
-        term.draw(|f| draw_ui(f, &rows))?;
+        term.draw(|f| {
+            draw_ui(
+                f,
+                &rows,
+                &figures,
+                &order,
+                &history,
+                view,
+                selected,
+                &fx,
+                &portfolio.base_currency,
+                status.as_deref(),
+            )
+        })?;
 
         if event::poll(Duration::from_millis(100))? {
             if let event::Event::Key(key) = event::read()? {
-                if key.code == event::KeyCode::Char('q') || key.code == event::KeyCode::Esc {
-                    break;
+                match key.code {
+                    event::KeyCode::Char('q') => break,
+                    event::KeyCode::Esc => {
+                        if view == ViewMode::Detail {
+                            view = ViewMode::Table;
+                        } else {
+                            break;
+                        }
+                    }
+                    event::KeyCode::Char('c') => {
+                        view = if view == ViewMode::Table {
+                            ViewMode::Detail
+                        } else {
+                            ViewMode::Table
+                        };
+                    }
+                    event::KeyCode::Char('v') => {
+                        sort = if sort == SortMode::Value {
+                            SortMode::None
+                        } else {
+                            SortMode::Value
+                        };
+                    }
+                    event::KeyCode::Char('s') => {
+                        sort = if sort == SortMode::Change {
+                            SortMode::None
+                        } else {
+                            SortMode::Change
+                        };
+                    }
+                    event::KeyCode::Up if !rows.is_empty() => {
+                        // Move through the displayed order, not config
+                        // order, so the highlight never jumps backwards
+                        // while a sort is active.
+                        if let Some(pos) = order.iter().position(|&i| i == selected) {
+                            selected = order[pos.saturating_sub(1)];
+                        }
+                    }
+                    event::KeyCode::Down if !rows.is_empty() => {
+                        if let Some(pos) = order.iter().position(|&i| i == selected) {
+                            selected = order[(pos + 1).min(order.len() - 1)];
+                        }
+                    }
+                    _ => {}
                 }
             }
         }
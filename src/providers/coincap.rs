@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::PriceProvider;
+
+pub struct CoinCapProvider {
+    client: Client,
+}
+
+impl CoinCapProvider {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Deserialize)]
+struct AssetEntry {
+    symbol: String,
+    #[serde(rename = "priceUsd")]
+    price_usd: String,
+}
+
+#[derive(Deserialize)]
+struct Resp {
+    data: Vec<AssetEntry>,
+}
+
+#[async_trait]
+impl PriceProvider for CoinCapProvider {
+    async fn quotes(&self, symbols: &[String]) -> Result<HashMap<String, f64>> {
+        if symbols.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let resp: Resp = self
+            .client
+            .get("https://api.coincap.io/v2/assets")
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let wanted: std::collections::HashSet<&str> =
+            symbols.iter().map(String::as_str).collect();
+
+        let mut out = HashMap::with_capacity(symbols.len());
+        for entry in resp.data {
+            if !wanted.contains(entry.symbol.as_str()) {
+                continue;
+            }
+            if let Ok(price) = entry.price_usd.parse::<f64>() {
+                out.insert(entry.symbol, price);
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::PriceProvider;
+
+const COINGECKO_COIN_LIST_URL: &str = "https://api.coingecko.com/api/v3/coins/list";
+
+pub struct CoinGeckoProvider {
+    client: Client,
+}
+
+impl CoinGeckoProvider {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Deserialize)]
+struct CoinListEntry {
+    id: String,
+    symbol: String,
+}
+
+/// Resolves CoinGecko coin ids (e.g. `"bitcoin"`) for `symbols` (e.g.
+/// `"BTC"`). `/simple/price` keys on CoinGecko's own ids, which aren't just
+/// the lowercased ticker, so this looks them up via `/coins/list` instead of
+/// assuming the two match. Returns a lowercased-symbol -> id map; empty on
+/// any lookup failure. Tickers aren't unique across CoinGecko's listings, so
+/// the first match wins, same as picking a default exchange for a symbol.
+async fn resolve_coin_ids(client: &Client, symbols: &[String]) -> HashMap<String, String> {
+    let wanted: HashSet<String> = symbols.iter().map(|s| s.to_lowercase()).collect();
+
+    let Ok(resp) = client.get(COINGECKO_COIN_LIST_URL).send().await else {
+        return HashMap::new();
+    };
+    let Ok(list) = resp.json::<Vec<CoinListEntry>>().await else {
+        return HashMap::new();
+    };
+
+    let mut out = HashMap::with_capacity(wanted.len());
+    for entry in list {
+        if wanted.contains(&entry.symbol) {
+            out.entry(entry.symbol).or_insert(entry.id);
+        }
+    }
+    out
+}
+
+#[async_trait]
+impl PriceProvider for CoinGeckoProvider {
+    async fn quotes(&self, symbols: &[String]) -> Result<HashMap<String, f64>> {
+        if symbols.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let symbol_to_id = resolve_coin_ids(&self.client, symbols).await;
+        if symbol_to_id.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids = symbol_to_id
+            .values()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(",");
+        let url = format!(
+            "https://api.coingecko.com/api/v3/simple/price?ids={ids}&vs_currencies=usd"
+        );
+
+        let resp: HashMap<String, HashMap<String, f64>> =
+            self.client.get(url).send().await?.json().await?;
+
+        let mut out = HashMap::with_capacity(symbols.len());
+        for symbol in symbols {
+            let Some(id) = symbol_to_id.get(&symbol.to_lowercase()) else {
+                continue;
+            };
+            if let Some(usd) = resp.get(id).and_then(|m| m.get("usd")) {
+                out.insert(symbol.clone(), *usd);
+            }
+        }
+        Ok(out)
+    }
+}
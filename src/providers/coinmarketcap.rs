@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::PriceProvider;
+
+pub struct CoinMarketCapProvider {
+    client: Client,
+    api_key: String,
+}
+
+impl CoinMarketCapProvider {
+    pub fn new(client: Client, api_key: String) -> Self {
+        Self { client, api_key }
+    }
+}
+
+#[derive(Deserialize)]
+struct RespInner {
+    #[serde(rename = "quote")]
+    quote: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Resp {
+    data: HashMap<String, RespInner>,
+}
+
+#[async_trait]
+impl PriceProvider for CoinMarketCapProvider {
+    async fn quotes(&self, symbols: &[String]) -> Result<HashMap<String, f64>> {
+        if symbols.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let url = format!(
+            "https://pro-api.coinmarketcap.com/v1/cryptocurrency/quotes/latest?symbol={symbols}&CMC_PRO_API_KEY={key}",
+            symbols = symbols.join(","),
+            key = self.api_key,
+        );
+
+        let resp: Resp = self.client.get(url).send().await?.json().await?;
+
+        let mut out = HashMap::with_capacity(symbols.len());
+        for (symbol, inner) in resp.data {
+            let Some(usd) = inner.quote.get("USD") else {
+                continue;
+            };
+            if let Some(price) = usd.get("price").and_then(|v| v.as_f64()) {
+                out.insert(symbol, price);
+            }
+        }
+        Ok(out)
+    }
+}
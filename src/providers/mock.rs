@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::PriceProvider;
+
+/// Returns a fixed, pre-configured price for each symbol it knows about.
+/// Useful for tests and for running the TUI offline against known numbers.
+pub struct ForcedPriceProvider {
+    prices: HashMap<String, f64>,
+}
+
+impl ForcedPriceProvider {
+    pub fn new(prices: HashMap<String, f64>) -> Self {
+        Self { prices }
+    }
+}
+
+#[async_trait]
+impl PriceProvider for ForcedPriceProvider {
+    async fn quotes(&self, symbols: &[String]) -> Result<HashMap<String, f64>> {
+        Ok(symbols
+            .iter()
+            .filter_map(|s| self.prices.get(s).map(|p| (s.clone(), *p)))
+            .collect())
+    }
+}
+
+/// Returns no quotes at all. Used when a provider is deliberately disabled.
+pub struct NoOpProvider;
+
+#[async_trait]
+impl PriceProvider for NoOpProvider {
+    async fn quotes(&self, _symbols: &[String]) -> Result<HashMap<String, f64>> {
+        Ok(HashMap::new())
+    }
+}
@@ -0,0 +1,74 @@
+//! Price data backends.
+//!
+//! `fetch_price` used to hard-code a single CoinMarketCap URL with an
+//! embedded API key. Everything that talks to a quote source now goes
+//! through the `PriceProvider` trait so a rate-limited or unreachable
+//! vendor can be swapped out per-asset or for the whole portfolio.
+
+mod coincap;
+mod coingecko;
+mod coinmarketcap;
+mod mock;
+
+pub use coincap::CoinCapProvider;
+pub use coingecko::CoinGeckoProvider;
+pub use coinmarketcap::CoinMarketCapProvider;
+pub use mock::{ForcedPriceProvider, NoOpProvider};
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+/// A source of live price quotes, keyed by symbol.
+///
+/// Implementations should return an entry for every symbol they were able
+/// to price; a symbol that fails to resolve is simply omitted rather than
+/// failing the whole batch, so callers can fall back to cached data for
+/// it instead of losing every other quote in the request.
+#[async_trait]
+pub trait PriceProvider: Send + Sync {
+    async fn quotes(&self, symbols: &[String]) -> Result<HashMap<String, f64>>;
+}
+
+/// Which backend an asset (or the portfolio as a whole) should use.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    #[default]
+    #[serde(alias = "cmc")]
+    CoinMarketCap,
+    CoinGecko,
+    CoinCap,
+    /// Returns fixed, pre-configured values. Handy for tests and for
+    /// running the TUI offline against known numbers.
+    Forced,
+    /// Returns no quotes at all.
+    NoOp,
+}
+
+/// Everything a provider might need, resolved from config/env ahead of time
+/// so the providers themselves stay free of config-parsing concerns.
+#[derive(Debug, Clone, Default)]
+pub struct ProviderSettings {
+    pub coinmarketcap_api_key: Option<String>,
+    pub forced_prices: HashMap<String, f64>,
+}
+
+pub fn build_provider(
+    kind: ProviderKind,
+    client: reqwest::Client,
+    settings: &ProviderSettings,
+) -> Box<dyn PriceProvider> {
+    match kind {
+        ProviderKind::CoinMarketCap => Box::new(CoinMarketCapProvider::new(
+            client,
+            settings.coinmarketcap_api_key.clone().unwrap_or_default(),
+        )),
+        ProviderKind::CoinGecko => Box::new(CoinGeckoProvider::new(client)),
+        ProviderKind::CoinCap => Box::new(CoinCapProvider::new(client)),
+        ProviderKind::Forced => Box::new(ForcedPriceProvider::new(settings.forced_prices.clone())),
+        ProviderKind::NoOp => Box::new(NoOpProvider),
+    }
+}
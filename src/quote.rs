@@ -0,0 +1,51 @@
+//! Resilient price caching with staleness detection.
+//!
+//! Fetch failures used to collapse straight into `0.0`, so one flaky
+//! request made an asset's value silently read as zero. `QuoteCache` keeps
+//! the last successfully fetched price per symbol and only ever overwrites
+//! it on another success; a failed fetch just leaves the old quote in
+//! place, to be flagged stale once it's older than the caller's TTL.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Quote {
+    pub price: f64,
+    pub fetched_at: Instant,
+}
+
+impl Quote {
+    pub fn is_stale(&self, ttl: Duration) -> bool {
+        self.fetched_at.elapsed() > ttl
+    }
+
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+}
+
+/// Keyed by symbol.
+#[derive(Debug, Default)]
+pub struct QuoteCache {
+    quotes: HashMap<String, Quote>,
+}
+
+impl QuoteCache {
+    /// Records a successful fetch. Never called with a failure; failures
+    /// should just leave the existing entry untouched.
+    pub fn update(&mut self, symbol: &str, price: f64) {
+        self.quotes.insert(
+            symbol.to_string(),
+            Quote {
+                price,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+
+    /// The last known quote for `symbol`, if we've ever fetched one.
+    pub fn get(&self, symbol: &str) -> Option<Quote> {
+        self.quotes.get(symbol).copied()
+    }
+}
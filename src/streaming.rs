@@ -0,0 +1,123 @@
+//! Push-based price updates over a WebSocket.
+//!
+//! Polling every asset on a fixed interval is laggy and hammers REST
+//! endpoints for no reason when the exchange can just push trades at us.
+//! `spawn_coincap_stream` opens a ticker socket, keeps a `watch::Receiver`
+//! current for as long as the process runs, and reconnects with backoff if
+//! the socket drops. Assets whose provider has no stream still go through
+//! the REST poll in `main`; this is a fallback-friendly addition, not a
+//! replacement for it.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use futures::StreamExt;
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::watch;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+const COINCAP_WS_URL: &str = "wss://ws.coincap.io/prices";
+const COINCAP_ASSETS_URL: &str = "https://api.coincap.io/v2/assets";
+
+/// Backoff schedule used between reconnect attempts after a dropped socket.
+const RECONNECT_BACKOFF: [Duration; 5] = [
+    Duration::from_secs(1),
+    Duration::from_secs(2),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+];
+
+#[derive(Deserialize)]
+struct AssetEntry {
+    id: String,
+    symbol: String,
+}
+
+#[derive(Deserialize)]
+struct AssetsResp {
+    data: Vec<AssetEntry>,
+}
+
+/// Resolves CoinCap asset ids (e.g. `"bitcoin"`) for `symbols` (e.g.
+/// `"BTC"`) via the same `/v2/assets` listing `CoinCapProvider` uses, so the
+/// streamed ticks — which CoinCap keys by id, not symbol — line up with the
+/// symbol-keyed `QuoteCache`. Returns an id -> symbol map; empty on any
+/// lookup failure, which the caller treats as "try again later".
+async fn resolve_asset_ids(client: &Client, symbols: &[String]) -> HashMap<String, String> {
+    let wanted: HashSet<&str> = symbols.iter().map(String::as_str).collect();
+
+    let Ok(resp) = client.get(COINCAP_ASSETS_URL).send().await else {
+        return HashMap::new();
+    };
+    let Ok(resp) = resp.json::<AssetsResp>().await else {
+        return HashMap::new();
+    };
+
+    resp.data
+        .into_iter()
+        .filter(|a| wanted.contains(a.symbol.as_str()))
+        .map(|a| (a.id, a.symbol))
+        .collect()
+}
+
+/// Subscribes to CoinCap's live price socket for `symbols` and spawns a
+/// background task that keeps the returned receiver up to date for as long
+/// as the process runs. While the socket is down the receiver just keeps
+/// returning the last prices it saw.
+pub fn spawn_coincap_stream(
+    client: Client,
+    symbols: Vec<String>,
+) -> watch::Receiver<HashMap<String, f64>> {
+    let (tx, rx) = watch::channel(HashMap::new());
+
+    tokio::spawn(async move {
+        let mut attempt = 0usize;
+
+        loop {
+            // The WS feed subscribes by asset id, not symbol, so resolve
+            // that mapping before every (re)connect attempt.
+            let id_to_symbol = resolve_asset_ids(&client, &symbols).await;
+            if id_to_symbol.is_empty() {
+                let wait = RECONNECT_BACKOFF[attempt.min(RECONNECT_BACKOFF.len() - 1)];
+                attempt += 1;
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+
+            let ids = id_to_symbol.keys().cloned().collect::<Vec<_>>().join(",");
+            let url = format!("{COINCAP_WS_URL}?assets={ids}");
+
+            if let Ok((mut ws, _)) = connect_async(&url).await {
+                attempt = 0;
+                while let Some(Ok(Message::Text(text))) = ws.next().await {
+                    let Ok(prices) = serde_json::from_str::<HashMap<String, String>>(&text) else {
+                        continue;
+                    };
+                    let mut updated = tx.borrow().clone();
+                    let mut changed = false;
+                    for (asset_id, price) in prices {
+                        let Some(symbol) = id_to_symbol.get(&asset_id) else {
+                            continue;
+                        };
+                        if let Ok(price) = price.parse::<f64>() {
+                            updated.insert(symbol.clone(), price);
+                            changed = true;
+                        }
+                    }
+                    if changed && tx.send(updated).is_err() {
+                        // No receivers left; nothing more to do.
+                        return;
+                    }
+                }
+            }
+
+            let wait = RECONNECT_BACKOFF[attempt.min(RECONNECT_BACKOFF.len() - 1)];
+            attempt += 1;
+            tokio::time::sleep(wait).await;
+        }
+    });
+
+    rx
+}